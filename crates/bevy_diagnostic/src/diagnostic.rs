@@ -97,9 +97,65 @@ impl Diagnostic {
         None
     }
 
+    /// Returns the smallest measurement that is greater than or equal to `percentile`% of the history (nearest-rank).
+    /// `percentile` is clamped to 0-100.
+    ///
+    /// This sorts a copy of the history on every call, so it costs `O(n log n)` in the history length. Use
+    /// [Diagnostic::percentiles] to compute several percentiles from a single sort.
+    pub fn percentile(&self, percentile: f64) -> Option<f64> {
+        self.percentiles(&[percentile])
+            .map(|percentiles| percentiles[0])
+    }
+
+    /// Returns the nearest-rank value of each of the given percentiles, in the same order, sorting the history once.
+    /// See [Diagnostic::percentile].
+    pub fn percentiles(&self, percentiles: &[f64]) -> Option<Vec<f64>> {
+        if self.history.is_empty() {
+            return None;
+        }
+
+        let mut values = self
+            .history
+            .iter()
+            .map(|measurement| measurement.value)
+            .collect::<Vec<_>>();
+        sort_values(&mut values);
+
+        Some(
+            percentiles
+                .iter()
+                .map(|percentile| nearest_rank(&values, *percentile))
+                .collect(),
+        )
+    }
+
     pub fn get_max_history_length(&self) -> usize {
         self.max_history_length
     }
+
+    /// Sets the number of measurements kept in the history, dropping the oldest ones if the history is too long.
+    /// The history always keeps at least one measurement.
+    pub fn set_max_history_length(&mut self, max_history_length: usize) {
+        let max_history_length = max_history_length.max(1);
+        while self.history.len() > max_history_length {
+            if let Some(removed_diagnostic) = self.history.pop_back() {
+                self.sum -= removed_diagnostic.value;
+            }
+        }
+
+        self.max_history_length = max_history_length;
+    }
+}
+
+/// Sorts measurement values in ascending order, for use with `nearest_rank`
+pub(crate) fn sort_values(values: &mut [f64]) {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+/// Returns the nearest-rank `percentile` (clamped to 0-100) of `sorted_values`, which must be sorted and non-empty
+pub(crate) fn nearest_rank(sorted_values: &[f64], percentile: f64) -> f64 {
+    let rank = (percentile * sorted_values.len() as f64 / 100.0).ceil() as usize;
+    sorted_values[rank.max(1).min(sorted_values.len()) - 1]
 }
 
 /// A collection of [Diagnostic]s
@@ -137,3 +193,87 @@ impl Diagnostics {
         self.diagnostics.values()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Diagnostic, DiagnosticId};
+
+    fn diagnostic_with(values: impl Iterator<Item = f64>, max_history_length: usize) -> Diagnostic {
+        let mut diagnostic = Diagnostic::new(DiagnosticId::default(), "test", max_history_length);
+        for value in values {
+            diagnostic.add_measurement(value);
+        }
+        diagnostic
+    }
+
+    #[test]
+    fn percentile_empty_history() {
+        let diagnostic = diagnostic_with(std::iter::empty(), 10);
+        assert_eq!(diagnostic.percentile(50.0), None);
+    }
+
+    #[test]
+    fn percentile_single_sample() {
+        let diagnostic = diagnostic_with(vec![3.0].into_iter(), 10);
+        assert_eq!(diagnostic.percentile(0.0), Some(3.0));
+        assert_eq!(diagnostic.percentile(50.0), Some(3.0));
+        assert_eq!(diagnostic.percentile(100.0), Some(3.0));
+    }
+
+    #[test]
+    fn percentile_known_history() {
+        // added in reverse so the result doesn't depend on insertion order
+        let diagnostic = diagnostic_with((1..=100).rev().map(|i| i as f64), 100);
+        assert_eq!(diagnostic.percentile(0.0), Some(1.0));
+        assert_eq!(diagnostic.percentile(50.0), Some(50.0));
+        assert_eq!(diagnostic.percentile(95.0), Some(95.0));
+        assert_eq!(diagnostic.percentile(99.0), Some(99.0));
+        assert_eq!(diagnostic.percentile(100.0), Some(100.0));
+    }
+
+    #[test]
+    fn percentile_is_clamped() {
+        let diagnostic = diagnostic_with((1..=100).map(|i| i as f64), 100);
+        assert_eq!(diagnostic.percentile(-10.0), Some(1.0));
+        assert_eq!(diagnostic.percentile(150.0), Some(100.0));
+    }
+
+    #[test]
+    fn set_max_history_length_shrinks_history() {
+        let mut diagnostic = diagnostic_with((1..=10).map(|i| i as f64), 10);
+        diagnostic.set_max_history_length(4);
+        assert_eq!(diagnostic.history_len(), 4);
+        assert_eq!(diagnostic.sum(), 7.0 + 8.0 + 9.0 + 10.0);
+        assert_eq!(diagnostic.average(), Some(8.5));
+
+        diagnostic.add_measurement(11.0);
+        assert_eq!(diagnostic.history_len(), 4);
+        assert_eq!(diagnostic.sum(), 8.0 + 9.0 + 10.0 + 11.0);
+    }
+
+    #[test]
+    fn percentiles_match_percentile() {
+        let diagnostic = diagnostic_with((1..=100).map(|i| i as f64), 100);
+        assert_eq!(
+            diagnostic.percentiles(&[99.0, 0.0, 95.0]),
+            Some(vec![99.0, 1.0, 95.0])
+        );
+        assert_eq!(
+            diagnostic_with(std::iter::empty(), 10).percentiles(&[50.0]),
+            None
+        );
+    }
+
+    #[test]
+    fn zero_max_history_length_is_clamped() {
+        let mut diagnostic = diagnostic_with((1..=3).map(|i| i as f64), 10);
+        diagnostic.set_max_history_length(0);
+        assert_eq!(diagnostic.history_len(), 1);
+
+        diagnostic.add_measurement(4.0);
+        diagnostic.add_measurement(5.0);
+        assert_eq!(diagnostic.history_len(), 1);
+        assert_eq!(diagnostic.get_max_history_length(), 1);
+        assert_eq!(diagnostic.sum(), 5.0);
+    }
+}
@@ -1,16 +1,46 @@
-use crate::{Diagnostic, DiagnosticId, Diagnostics};
+use crate::{
+    diagnostic::{nearest_rank, sort_values},
+    Diagnostic, DiagnosticId, Diagnostics,
+};
 use bevy_app::prelude::*;
 use bevy_core::Time;
 use bevy_ecs::{IntoQuerySystem, Res, ResMut};
+use std::collections::VecDeque;
 
-/// Adds "frame time" diagnostic to an App, specifically "frame time" and "fps"
-#[derive(Default)]
-pub struct FrameTimeDiagnosticsPlugin;
+/// Adds "frame time" diagnostic to an App, specifically "frame time", "fps" and the 95th/99th percentile frame times
+pub struct FrameTimeDiagnosticsPlugin {
+    /// The number of frame times the percentile diagnostics are computed from. This is separate from the "frame time"
+    /// history so that it doesn't change the "fps" averaging window. Larger windows make p99 more meaningful, but the
+    /// window is sorted every frame, so the cost grows with its length. Values below 1 are treated as 1.
+    pub percentile_history_length: usize,
+}
+
+/// The frame times the [FrameTimeDiagnosticsPlugin] percentiles are computed from
+pub(crate) struct FrameTimePercentileState {
+    frame_times: VecDeque<f64>,
+    max_length: usize,
+    sorted: Vec<f64>,
+}
+
+impl Default for FrameTimeDiagnosticsPlugin {
+    fn default() -> Self {
+        FrameTimeDiagnosticsPlugin {
+            percentile_history_length: 300,
+        }
+    }
+}
 
 impl Plugin for FrameTimeDiagnosticsPlugin {
     fn build(&self, app: &mut bevy_app::AppBuilder) {
-        app.add_startup_system(Self::setup_system.system())
-            .add_system(Self::diagnostic_system.system());
+        let max_length = self.percentile_history_length.max(1);
+        app.add_resource(FrameTimePercentileState {
+            frame_times: VecDeque::with_capacity(max_length),
+            max_length,
+            sorted: Vec::with_capacity(max_length),
+        })
+        .add_startup_system(Self::setup_system.system())
+        .add_system(Self::diagnostic_system.system())
+        .add_system(Self::percentile_diagnostic_system.system());
     }
 }
 
@@ -18,10 +48,16 @@ impl FrameTimeDiagnosticsPlugin {
     pub const FPS: DiagnosticId = DiagnosticId::from_u128(288146834822086093791974408528866909483);
     pub const FRAME_TIME: DiagnosticId =
         DiagnosticId::from_u128(54021991829115352065418785002088010276);
+    pub const FRAME_TIME_P95: DiagnosticId =
+        DiagnosticId::from_u128(77217477661630556024443434516015278215);
+    pub const FRAME_TIME_P99: DiagnosticId =
+        DiagnosticId::from_u128(314643212152753993284564954321806128487);
 
     pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
         diagnostics.add(Diagnostic::new(Self::FRAME_TIME, "frame_time", 20));
         diagnostics.add(Diagnostic::new(Self::FPS, "fps", 20));
+        diagnostics.add(Diagnostic::new(Self::FRAME_TIME_P95, "frame_time_p95", 20));
+        diagnostics.add(Diagnostic::new(Self::FRAME_TIME_P99, "frame_time_p99", 20));
     }
 
     pub fn diagnostic_system(mut diagnostics: ResMut<Diagnostics>, time: Res<Time>) {
@@ -47,4 +83,27 @@ impl FrameTimeDiagnosticsPlugin {
             diagnostics.add_measurement(Self::FPS, fps);
         }
     }
+
+    fn percentile_diagnostic_system(
+        mut state: ResMut<FrameTimePercentileState>,
+        mut diagnostics: ResMut<Diagnostics>,
+        time: Res<Time>,
+    ) {
+        if time.delta_seconds_f64 == 0.0 {
+            return;
+        }
+
+        let state = &mut *state;
+        if state.frame_times.len() >= state.max_length {
+            state.frame_times.pop_back();
+        }
+        state.frame_times.push_front(time.delta_seconds_f64);
+
+        // sort once and read both ranks from the same copy
+        state.sorted.clear();
+        state.sorted.extend(state.frame_times.iter());
+        sort_values(&mut state.sorted);
+        diagnostics.add_measurement(Self::FRAME_TIME_P95, nearest_rank(&state.sorted, 95.0));
+        diagnostics.add_measurement(Self::FRAME_TIME_P99, nearest_rank(&state.sorted, 99.0));
+    }
 }
@@ -158,6 +158,12 @@ impl Plugin for RenderPlugin {
                 shader::clear_shader_defs_system.system(),
             );
 
+        #[cfg(feature = "render_graph_debug")]
+        app.add_startup_system_to_stage(
+            bevy_app::startup_stage::POST_STARTUP,
+            render_graph::render_graph_debug_system.system(),
+        );
+
         if app.resources().get::<Msaa>().is_none() {
             app.init_resource::<Msaa>();
         }
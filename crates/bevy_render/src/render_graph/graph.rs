@@ -278,6 +278,60 @@ impl RenderGraph {
     pub fn take_commands(&mut self) -> Commands {
         std::mem::take(&mut self.commands)
     }
+
+    /// Returns a human readable listing of every node (sorted by name) and its output edges, one line per entry
+    pub fn describe(&self) -> Vec<String> {
+        let node_name = |id: NodeId| match self.get_node_state(id) {
+            Ok(NodeState {
+                name: Some(name), ..
+            }) => name.to_string(),
+            _ => format!("{:?}", id),
+        };
+
+        let mut nodes = self
+            .iter_nodes()
+            .map(|node_state| (node_name(node_state.id), node_state))
+            .collect::<Vec<_>>();
+        nodes.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut lines = vec![format!("render graph nodes ({}):", nodes.len())];
+        for (name, node_state) in nodes.iter() {
+            lines.push(format!("  {}", name));
+            for edge in node_state.edges.output_edges.iter() {
+                match *edge {
+                    Edge::SlotEdge {
+                        input_node,
+                        input_index,
+                        output_index,
+                        ..
+                    } => {
+                        let output_slot = node_state
+                            .output_slots
+                            .get_slot(output_index)
+                            .map(|slot| slot.info.name.to_string())
+                            .unwrap_or_else(|_| output_index.to_string());
+                        let input_slot = self
+                            .get_node_state(input_node)
+                            .and_then(|input_state| input_state.input_slots.get_slot(input_index))
+                            .map(|slot| slot.info.name.to_string())
+                            .unwrap_or_else(|_| input_index.to_string());
+                        lines.push(format!(
+                            "    {}.{} -> {}.{}",
+                            name,
+                            output_slot,
+                            node_name(input_node),
+                            input_slot
+                        ));
+                    }
+                    Edge::NodeEdge { input_node, .. } => {
+                        lines.push(format!("    {} -> {}", name, node_name(input_node)));
+                    }
+                }
+            }
+        }
+
+        lines
+    }
 }
 
 impl Debug for RenderGraph {
@@ -404,6 +458,35 @@ mod tests {
         assert!(output_nodes("D", &graph).is_empty(), "D has no outputs");
     }
 
+    #[test]
+    pub fn test_describe() {
+        let mut graph = RenderGraph::default();
+        graph.add_node("A", TestNode::new(0, 1));
+        graph.add_node("B", TestNode::new(0, 0));
+        graph.add_node("C", TestNode::new(1, 0));
+        let unnamed_id = graph.add_node("unnamed", TestNode::new(0, 0));
+
+        graph.add_slot_edge("A", "out_0", "C", "in_0").unwrap();
+        graph.add_node_edge("B", "C").unwrap();
+        graph.add_node_edge("B", "unnamed").unwrap();
+        graph.get_node_state_mut(unnamed_id).unwrap().name = None;
+
+        let unnamed = format!("{:?}", unnamed_id);
+        assert_eq!(
+            graph.describe(),
+            vec![
+                "render graph nodes (4):".to_string(),
+                "  A".to_string(),
+                "    A.out_0 -> C.in_0".to_string(),
+                "  B".to_string(),
+                "    B -> C".to_string(),
+                format!("    B -> {}", unnamed),
+                "  C".to_string(),
+                format!("  {}", unnamed),
+            ]
+        );
+    }
+
     #[test]
     pub fn test_get_node_typed() {
         struct MyNode {
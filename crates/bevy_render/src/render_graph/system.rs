@@ -1,4 +1,6 @@
 use super::RenderGraph;
+#[cfg(feature = "render_graph_debug")]
+use bevy_ecs::Res;
 use bevy_ecs::{Resources, World};
 
 pub fn render_graph_schedule_executor_system(world: &mut World, resources: &mut Resources) {
@@ -17,3 +19,12 @@ pub fn render_graph_schedule_executor_system(world: &mut World, resources: &mut
         render_graph.set_schedule(schedule);
     }
 }
+
+/// Logs every node in the [RenderGraph] along with its output edges. [RenderPlugin](crate::RenderPlugin) runs this once in the
+/// `POST_STARTUP` stage, after plugins and startup systems have added their nodes.
+#[cfg(feature = "render_graph_debug")]
+pub fn render_graph_debug_system(render_graph: Res<RenderGraph>) {
+    for line in render_graph.describe() {
+        log::info!("{}", line);
+    }
+}